@@ -21,7 +21,7 @@ struct MessageHeader {
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 struct Response {
-    #[serde(rename = "htv:headers")]
+    #[serde(rename = "htv:headers", default)]
     headers: Vec<MessageHeader>,
     #[serde(rename = "htv:statusCodeValue")]
     status_code_value: Option<usize>,
@@ -34,7 +34,7 @@ struct Form {
     method_name: Option<Method>,
 }
 
-use mini::{Buildable, Builder};
+use mini::{BuildError, Buildable, Builder};
 
 #[derive(Default)]
 struct ResponseBuilder {
@@ -66,6 +66,16 @@ impl Builder for ResponseBuilder {
             status_code_value,
         }
     }
+
+    fn try_build(self) -> Result<Response, BuildError> {
+        if let Some(status_code_value) = self.status_code_value {
+            if !(100..=599).contains(&status_code_value) {
+                return Err(BuildError::InvalidStatusCode(status_code_value));
+            }
+        }
+
+        Ok(self.build())
+    }
 }
 
 impl Buildable for Response {
@@ -76,6 +86,26 @@ impl Buildable for Response {
     }
 }
 
+impl mini::StatusCode for Response {
+    fn status_code_value(&self) -> Option<usize> {
+        self.status_code_value
+    }
+
+    /// Checks that every header this response declares via `htv:headers` (by `field_name`) is
+    /// present, case-insensitively, among `present_headers`.
+    fn headers_present(&self, present_headers: &[&str]) -> bool {
+        let present: Vec<String> = present_headers
+            .iter()
+            .map(|header| header.to_ascii_lowercase())
+            .collect();
+
+        self.headers
+            .iter()
+            .filter_map(|header| header.field_name.as_deref())
+            .all(|name| present.contains(&name.to_ascii_lowercase()))
+    }
+}
+
 #[derive(Default)]
 struct FormBuilder {
     method_name: Option<Method>,
@@ -111,15 +141,29 @@ impl Buildable for Form {
 
 pub(crate) mod mini {
     use crate::hlist::Nil;
-    use crate::thing::DefaultedFormOperations;
+    use crate::thing::{DefaultedFormOperations, FormOperation};
     use serde::{Deserialize, Serialize};
     use serde_with::*;
     use std::borrow::Cow;
+    use std::collections::BTreeMap;
 
     pub trait Builder: Default {
         type B: Buildable;
 
         fn build(self) -> Self::B;
+
+        /// Like [`Self::build`], but validates the accumulated fields first and reports the
+        /// first offending one instead of producing a structurally invalid value.
+        ///
+        /// The default implementation performs no validation and always succeeds; builders that
+        /// carry fields with a well-formed vocabulary (a non-empty `href`, a syntactically valid
+        /// MIME type, an in-range status code, ...) should override it.
+        fn try_build(self) -> Result<Self::B, BuildError>
+        where
+            Self: Sized,
+        {
+            Ok(self.build())
+        }
     }
 
     pub trait Buildable: Default {
@@ -128,6 +172,77 @@ pub(crate) mod mini {
         fn builder() -> Self::B;
     }
 
+    /// An error produced by [`Builder::try_build`] when the accumulated fields do not describe a
+    /// valid Thing Description fragment.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum BuildError {
+        /// `href` was empty or contained only whitespace.
+        EmptyHref,
+        /// A field expected to hold a MIME media type did not look like one.
+        InvalidMediaType { field: &'static str, value: String },
+        /// A field expected to hold an HTTP token (e.g. a content coding) did not look like one.
+        InvalidToken { field: &'static str, value: String },
+        /// `htv:statusCodeValue` was outside the valid HTTP status code range (100..=599).
+        InvalidStatusCode(usize),
+    }
+
+    impl std::fmt::Display for BuildError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                BuildError::EmptyHref => write!(f, "href must not be empty"),
+                BuildError::InvalidMediaType { field, value } => {
+                    write!(f, "`{field}` is not a valid media type: {value:?}")
+                }
+                BuildError::InvalidToken { field, value } => {
+                    write!(f, "`{field}` is not a valid token: {value:?}")
+                }
+                BuildError::InvalidStatusCode(code) => {
+                    write!(f, "{code} is not a valid HTTP status code")
+                }
+            }
+        }
+    }
+
+    impl std::error::Error for BuildError {}
+
+    fn is_token_char(c: char) -> bool {
+        c.is_ascii_graphic()
+            && !matches!(
+                c,
+                '(' | ')'
+                    | '<'
+                    | '>'
+                    | '@'
+                    | ','
+                    | ';'
+                    | ':'
+                    | '\\'
+                    | '"'
+                    | '/'
+                    | '['
+                    | ']'
+                    | '?'
+                    | '='
+                    | '{'
+                    | '}'
+            )
+    }
+
+    fn is_valid_token(s: &str) -> bool {
+        !s.is_empty() && s.chars().all(is_token_char)
+    }
+
+    /// Checks that `s` looks like a syntactically valid `type/subtype` MIME media type, ignoring
+    /// any `;`-separated parameters (e.g. the `charset=utf-8` in `application/ld+json;
+    /// charset=utf-8`).
+    fn is_valid_media_type(s: &str) -> bool {
+        let essence = s.split(';').next().unwrap_or("").trim();
+        match essence.split_once('/') {
+            Some((ty, subty)) => is_valid_token(ty) && is_valid_token(subty),
+            None => false,
+        }
+    }
+
     impl Builder for Nil {
         type B = Nil;
 
@@ -217,6 +332,225 @@ pub(crate) mod mini {
                 other,
             }
         }
+
+        fn try_build(self) -> Result<Self::B, BuildError> {
+            let ExpectedResponseBuilder {
+                content_type,
+                other,
+            } = self;
+
+            if !is_valid_media_type(&content_type) {
+                return Err(BuildError::InvalidMediaType {
+                    field: "content_type",
+                    value: content_type,
+                });
+            }
+
+            let other = other.try_build()?;
+
+            Ok(ExpectedResponse {
+                content_type,
+                other,
+            })
+        }
+    }
+
+    /// An [IANA HTTP content coding](https://www.iana.org/assignments/http-parameters/http-parameters.xhtml#content-coding).
+    ///
+    /// Unknown codings round-trip losslessly through [`Self::Other`] instead of being rejected,
+    /// so a TD using a vendor-specific coding still deserializes.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub enum ContentCoding {
+        Gzip,
+        Deflate,
+        Br,
+        Compress,
+        Identity,
+        Other(String),
+    }
+
+    impl ContentCoding {
+        fn as_str(&self) -> &str {
+            match self {
+                Self::Gzip => "gzip",
+                Self::Deflate => "deflate",
+                Self::Br => "br",
+                Self::Compress => "compress",
+                Self::Identity => "identity",
+                Self::Other(other) => other,
+            }
+        }
+    }
+
+    impl std::fmt::Display for ContentCoding {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+
+    impl From<&str> for ContentCoding {
+        fn from(s: &str) -> Self {
+            match s {
+                "gzip" => Self::Gzip,
+                "deflate" => Self::Deflate,
+                "br" => Self::Br,
+                "compress" => Self::Compress,
+                "identity" => Self::Identity,
+                other => Self::Other(other.to_string()),
+            }
+        }
+    }
+
+    impl Serialize for ContentCoding {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ContentCoding {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let s = String::deserialize(deserializer)?;
+            Ok(Self::from(s.as_str()))
+        }
+    }
+
+    /// A reference to a security scheme used by a [`Form`], either one of the known
+    /// `KnownSecuritySchemeSubtype` names or a vendor-defined one.
+    ///
+    /// Unknown names round-trip losslessly through [`Self::Other`] instead of being rejected.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub enum SecuritySchemeRef {
+        NoSec,
+        Basic,
+        Digest,
+        Bearer,
+        Psk,
+        OAuth2,
+        ApiKey,
+        Combo,
+        Other(String),
+    }
+
+    impl SecuritySchemeRef {
+        fn as_str(&self) -> &str {
+            match self {
+                Self::NoSec => "nosec",
+                Self::Basic => "basic",
+                Self::Digest => "digest",
+                Self::Bearer => "bearer",
+                Self::Psk => "psk",
+                Self::OAuth2 => "oauth2",
+                Self::ApiKey => "apikey",
+                Self::Combo => "combo",
+                Self::Other(other) => other,
+            }
+        }
+    }
+
+    impl std::fmt::Display for SecuritySchemeRef {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+
+    impl From<&str> for SecuritySchemeRef {
+        fn from(s: &str) -> Self {
+            match s {
+                "nosec" => Self::NoSec,
+                "basic" => Self::Basic,
+                "digest" => Self::Digest,
+                "bearer" => Self::Bearer,
+                "psk" => Self::Psk,
+                "oauth2" => Self::OAuth2,
+                "apikey" => Self::ApiKey,
+                "combo" => Self::Combo,
+                other => Self::Other(other.to_string()),
+            }
+        }
+    }
+
+    impl Serialize for SecuritySchemeRef {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for SecuritySchemeRef {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let s = String::deserialize(deserializer)?;
+            Ok(Self::from(s.as_str()))
+        }
+    }
+
+    /// A transport-level subprotocol used to carry observable-property or event notifications
+    /// over a [`Form`], such as the ones referenced by the WoT HTTP/SSE binding.
+    ///
+    /// Unknown names round-trip losslessly through [`Self::Other`] instead of being rejected.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub enum Subprotocol {
+        Sse,
+        LongPoll,
+        WebSub,
+        Other(String),
+    }
+
+    impl Subprotocol {
+        fn as_str(&self) -> &str {
+            match self {
+                Self::Sse => "sse",
+                Self::LongPoll => "longpoll",
+                Self::WebSub => "websub",
+                Self::Other(other) => other,
+            }
+        }
+    }
+
+    impl std::fmt::Display for Subprotocol {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+
+    impl From<&str> for Subprotocol {
+        fn from(s: &str) -> Self {
+            match s {
+                "sse" => Self::Sse,
+                "longpoll" => Self::LongPoll,
+                "websub" => Self::WebSub,
+                other => Self::Other(other.to_string()),
+            }
+        }
+    }
+
+    impl Serialize for Subprotocol {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Subprotocol {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let s = String::deserialize(deserializer)?;
+            Ok(Self::from(s.as_str()))
+        }
     }
 
     #[serde_as]
@@ -233,16 +567,13 @@ pub(crate) mod mini {
         #[serde(default = "Form::<Nil>::default_content_type")]
         pub content_type: Cow<'static, str>,
 
-        // TODO: check if the subset of possible values is limited by the [IANA HTTP content coding
-        // registry](https://www.iana.org/assignments/http-parameters/http-parameters.xhtml#content-coding).
-        pub content_coding: Option<String>,
+        pub content_coding: Option<ContentCoding>,
 
-        pub subprotocol: Option<String>,
+        pub subprotocol: Option<Subprotocol>,
 
-        // FIXME: use variant names of KnownSecuritySchemeSubtype + "other" string variant
         #[serde(default)]
         #[serde_as(as = "Option<OneOrMany<_>>")]
-        pub security: Option<Vec<String>>,
+        pub security: Option<Vec<SecuritySchemeRef>>,
 
         #[serde(default)]
         #[serde_as(as = "Option<OneOrMany<_>>")]
@@ -262,6 +593,187 @@ pub(crate) mod mini {
         }
     }
 
+    impl<T: Buildable, E: Buildable> Default for Form<T, E> {
+        fn default() -> Self {
+            Form {
+                op: DefaultedFormOperations::default(),
+                href: String::default(),
+                content_type: Form::<Nil>::default_content_type(),
+                content_coding: None,
+                subprotocol: None,
+                security: None,
+                scopes: None,
+                response: None,
+                additional_response: None,
+                other: T::default(),
+            }
+        }
+    }
+
+    #[derive(Default)]
+    pub struct FormBuilder<T: Builder = Nil, E: Builder = Nil> {
+        pub op: DefaultedFormOperations,
+        pub href: String,
+        pub content_type: Cow<'static, str>,
+        pub content_coding: Option<ContentCoding>,
+        pub subprotocol: Option<Subprotocol>,
+        pub security: Option<Vec<SecuritySchemeRef>>,
+        pub scopes: Option<Vec<String>>,
+        pub response: Option<ExpectedResponse<E::B>>,
+        pub additional_response: Option<AdditionalExpectedResponse<E::B>>,
+        pub other: T,
+    }
+
+    impl<T: Builder, E: Builder> FormBuilder<T, E> {
+        pub fn op(mut self, op: DefaultedFormOperations) -> Self {
+            self.op = op;
+            self
+        }
+
+        pub fn href(mut self, href: impl Into<String>) -> Self {
+            self.href = href.into();
+            self
+        }
+
+        pub fn content_type(mut self, content_type: impl Into<Cow<'static, str>>) -> Self {
+            self.content_type = content_type.into();
+            self
+        }
+
+        pub fn content_coding(mut self, content_coding: ContentCoding) -> Self {
+            self.content_coding = Some(content_coding);
+            self
+        }
+
+        pub fn subprotocol(mut self, subprotocol: Subprotocol) -> Self {
+            self.subprotocol = Some(subprotocol);
+            self
+        }
+
+        pub fn security(mut self, security: Vec<SecuritySchemeRef>) -> Self {
+            self.security = Some(security);
+            self
+        }
+
+        pub fn scopes(mut self, scopes: Vec<String>) -> Self {
+            self.scopes = Some(scopes);
+            self
+        }
+
+        pub fn response(mut self, response: ExpectedResponse<E::B>) -> Self {
+            self.response = Some(response);
+            self
+        }
+
+        pub fn additional_response(
+            mut self,
+            additional_response: AdditionalExpectedResponse<E::B>,
+        ) -> Self {
+            self.additional_response = Some(additional_response);
+            self
+        }
+
+        pub fn other(self, f: fn(T) -> T) -> Self {
+            let Self {
+                op,
+                href,
+                content_type,
+                content_coding,
+                subprotocol,
+                security,
+                scopes,
+                response,
+                additional_response,
+                other,
+            } = self;
+            let other = f(other);
+
+            Self {
+                op,
+                href,
+                content_type,
+                content_coding,
+                subprotocol,
+                security,
+                scopes,
+                response,
+                additional_response,
+                other,
+            }
+        }
+    }
+
+    impl<T, E> Buildable for Form<T, E>
+    where
+        T: Buildable,
+        T::B: Builder,
+        E: Buildable,
+        E::B: Builder,
+    {
+        type B = FormBuilder<T::B, E::B>;
+
+        fn builder() -> Self::B {
+            FormBuilder::default()
+        }
+    }
+
+    impl<T: Builder, E: Builder> Builder for FormBuilder<T, E> {
+        type B = Form<T::B, E::B>;
+
+        fn build(self) -> Self::B {
+            let FormBuilder {
+                op,
+                href,
+                content_type,
+                content_coding,
+                subprotocol,
+                security,
+                scopes,
+                response,
+                additional_response,
+                other,
+            } = self;
+            let other = other.build();
+
+            Form {
+                op,
+                href,
+                content_type,
+                content_coding,
+                subprotocol,
+                security,
+                scopes,
+                response,
+                additional_response,
+                other,
+            }
+        }
+
+        fn try_build(self) -> Result<Self::B, BuildError> {
+            if self.href.trim().is_empty() {
+                return Err(BuildError::EmptyHref);
+            }
+
+            if !is_valid_media_type(&self.content_type) {
+                return Err(BuildError::InvalidMediaType {
+                    field: "content_type",
+                    value: self.content_type.to_string(),
+                });
+            }
+
+            if let Some(ContentCoding::Other(raw)) = &self.content_coding {
+                if !is_valid_token(raw) {
+                    return Err(BuildError::InvalidToken {
+                        field: "content_coding",
+                        value: raw.clone(),
+                    });
+                }
+            }
+
+            Ok(self.build())
+        }
+    }
+
     #[serde_as]
     #[skip_serializing_none]
     #[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
@@ -285,12 +797,629 @@ pub(crate) mod mini {
         #[serde(flatten)]
         pub other: T,
     }
+
+    fn form_has_op(op: &DefaultedFormOperations, target: FormOperation) -> bool {
+        match op {
+            DefaultedFormOperations::Custom(ops) => ops.contains(&target),
+            DefaultedFormOperations::Default => false,
+        }
+    }
+
+    /// A form paired with the decoded subprotocol and content type a consumer should use to
+    /// subscribe to it, returned by [`InteractionAffordance::subscription`].
+    #[derive(Debug)]
+    pub struct Subscription<'a, F: Buildable = Nil, R: Buildable = Nil> {
+        pub form: &'a Form<F, R>,
+        pub subprotocol: Option<Subprotocol>,
+        pub content_type: Cow<'static, str>,
+    }
+
+    impl<T: Buildable, F: Buildable, R: Buildable> InteractionAffordance<T, F, R> {
+        /// Forms whose `op` explicitly names `observeproperty`.
+        ///
+        /// Known gap: [`DefaultedFormOperations::Default`] is never treated as implying
+        /// `observeproperty`, since resolving the implicit default requires knowing whether this
+        /// affordance is a property, action, or event, and that kind isn't tracked here. A form
+        /// that omits `op` in reliance on the affordance kind implying it is not returned.
+        pub fn observe_forms(&self) -> impl Iterator<Item = &Form<F, R>> {
+            self.forms
+                .iter()
+                .filter(|form| form_has_op(&form.op, FormOperation::ObserveProperty))
+        }
+
+        /// Forms whose `op` explicitly names `subscribeevent`.
+        ///
+        /// Known gap: same as [`Self::observe_forms`] — a form that relies on the implicit
+        /// default `op` for an event affordance (which, per the TD spec, does imply
+        /// `subscribeevent`) is not returned, because [`DefaultedFormOperations::Default`]
+        /// carries no affordance kind to resolve against.
+        pub fn subscribe_event_forms(&self) -> impl Iterator<Item = &Form<F, R>> {
+            self.forms
+                .iter()
+                .filter(|form| form_has_op(&form.op, FormOperation::SubscribeEvent))
+        }
+
+        /// The first form (if any) whose `op` explicitly names `observeproperty`, or failing
+        /// that the first one naming `subscribeevent`, paired with its decoded subprotocol and
+        /// content type as a [`Subscription`].
+        ///
+        /// Inherits the known gap on [`Self::observe_forms`]/[`Self::subscribe_event_forms`]: a
+        /// form relying on an implicit default `op` is not considered.
+        pub fn subscription(&self) -> Option<Subscription<'_, F, R>> {
+            self.observe_forms()
+                .chain(self.subscribe_event_forms())
+                .next()
+                .map(|form| Subscription {
+                    form,
+                    subprotocol: form.subprotocol.clone(),
+                    content_type: form.content_type.clone(),
+                })
+        }
+    }
+
+    impl<T: Buildable, E: Buildable> Form<T, E> {
+        /// Expand [`Self::href`] as an [RFC 6570](https://www.rfc-editor.org/rfc/rfc6570) URI
+        /// Template against `vars`, producing a concrete request URL.
+        ///
+        /// Variables that are not present in `vars` are treated as undefined and omitted from
+        /// the expansion, as mandated by the RFC.
+        pub fn expand_href(
+            &self,
+            vars: &BTreeMap<String, uri_template::UriValue>,
+        ) -> Result<String, uri_template::ExpandError> {
+            uri_template::expand(&self.href, vars)
+        }
+    }
+
+    /// Implemented by a [`Form`]'s `E` (response-extension) type parameter when it carries a
+    /// transport-specific status code, such as the HTTP binding's `htv:statusCodeValue`.
+    ///
+    /// This lets [`Form::classify_response`] match a real response's status code against the
+    /// declared `response`/`additional_response` without `Form` itself knowing about any
+    /// particular transport.
+    pub trait StatusCode {
+        fn status_code_value(&self) -> Option<usize>;
+
+        /// Returns whether every header this response extension requires (e.g. the HTTP
+        /// binding's `htv:headers` field names) is present, case-insensitively, in
+        /// `present_headers`.
+        ///
+        /// Extensions with no header requirements (e.g. [`Nil`]) always return `true`.
+        fn headers_present(&self, present_headers: &[&str]) -> bool {
+            let _ = present_headers;
+            true
+        }
+    }
+
+    impl StatusCode for Nil {
+        fn status_code_value(&self) -> Option<usize> {
+            None
+        }
+    }
+
+    /// The outcome of matching a real response against a [`Form`]'s declared
+    /// `response`/`additional_response`, as returned by [`Form::classify_response`].
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum ResponseOutcome {
+        /// The response matched the declared `response`, or a declared `additional_response`
+        /// with `success: true`.
+        Success { content_type: String },
+        /// The response matched a declared `additional_response` with `success: false`.
+        Error { status: u16, content_type: String },
+        /// The response matched none of the declared responses.
+        Unexpected,
+    }
+
+    fn content_type_matches(expected: &str, actual: &str) -> bool {
+        let base = |s: &str| s.split(';').next().unwrap_or("").trim().to_ascii_lowercase();
+        base(expected) == base(actual)
+    }
+
+    impl<T: Buildable, E: Buildable + StatusCode> Form<T, E> {
+        /// Match a real HTTP response against this form's declared `response` and
+        /// `additional_response`, turning `htv:statusCodeValue` and the response's content type
+        /// into a typed [`ResponseOutcome`] instead of leaving the caller to re-derive it from
+        /// raw status codes.
+        ///
+        /// A declared response without a status code matches any status, so long as the content
+        /// type agrees.
+        ///
+        /// `present_headers` lists the header field names actually present on the real
+        /// response; a declared response whose `htv:headers` are not all accounted for among
+        /// them is treated as not matching, per [`StatusCode::headers_present`].
+        pub fn classify_response(
+            &self,
+            status: u16,
+            content_type: &str,
+            present_headers: &[&str],
+        ) -> ResponseOutcome {
+            if let Some(response) = &self.response {
+                let status_matches = response
+                    .other
+                    .status_code_value()
+                    .map_or(true, |expected| expected == status as usize);
+
+                if status_matches
+                    && content_type_matches(&response.content_type, content_type)
+                    && response.other.headers_present(present_headers)
+                {
+                    return ResponseOutcome::Success {
+                        content_type: content_type.to_string(),
+                    };
+                }
+            }
+
+            if let Some(additional) = &self.additional_response {
+                let status_matches = additional
+                    .other
+                    .status_code_value()
+                    .map_or(true, |expected| expected == status as usize);
+
+                if status_matches
+                    && content_type_matches(&additional.content_type, content_type)
+                    && additional.other.headers_present(present_headers)
+                {
+                    return if additional.success {
+                        ResponseOutcome::Success {
+                            content_type: content_type.to_string(),
+                        }
+                    } else {
+                        ResponseOutcome::Error {
+                            status,
+                            content_type: content_type.to_string(),
+                        }
+                    };
+                }
+            }
+
+            ResponseOutcome::Unexpected
+        }
+    }
+
+    /// RFC 6570 URI Template expansion (level 4), used by [`Form::expand_href`].
+    pub mod uri_template {
+        use std::collections::BTreeMap;
+        use std::fmt;
+
+        /// A value bound to a URI Template variable.
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum UriValue {
+            Scalar(String),
+            List(Vec<String>),
+            Assoc(Vec<(String, String)>),
+        }
+
+        /// An error produced while expanding a URI Template.
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum ExpandError {
+            /// A `{` was never closed by a matching `}`.
+            UnterminatedExpression,
+            /// An expression (`{}`) had no varspecs in it.
+            EmptyExpression,
+            /// A `:N` prefix modifier did not carry a valid length.
+            InvalidPrefixLength(String),
+        }
+
+        impl fmt::Display for ExpandError {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    ExpandError::UnterminatedExpression => {
+                        write!(f, "unterminated `{{...}}` expression")
+                    }
+                    ExpandError::EmptyExpression => write!(f, "empty `{{}}` expression"),
+                    ExpandError::InvalidPrefixLength(raw) => {
+                        write!(f, "invalid prefix length `:{raw}`")
+                    }
+                }
+            }
+        }
+
+        impl std::error::Error for ExpandError {}
+
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum Operator {
+            Simple,
+            Reserved,
+            Fragment,
+            Label,
+            Path,
+            PathStyle,
+            Query,
+            Continuation,
+        }
+
+        impl Operator {
+            fn from_char(c: char) -> Option<Self> {
+                match c {
+                    '+' => Some(Self::Reserved),
+                    '#' => Some(Self::Fragment),
+                    '.' => Some(Self::Label),
+                    '/' => Some(Self::Path),
+                    ';' => Some(Self::PathStyle),
+                    '?' => Some(Self::Query),
+                    '&' => Some(Self::Continuation),
+                    _ => None,
+                }
+            }
+
+            fn prefix(self) -> Option<char> {
+                match self {
+                    Self::Simple | Self::Reserved => None,
+                    Self::Fragment => Some('#'),
+                    Self::Label => Some('.'),
+                    Self::Path => Some('/'),
+                    Self::PathStyle => Some(';'),
+                    Self::Query => Some('?'),
+                    Self::Continuation => Some('&'),
+                }
+            }
+
+            fn separator(self) -> char {
+                match self {
+                    Self::Simple | Self::Reserved | Self::Fragment => ',',
+                    Self::Label => '.',
+                    Self::Path => '/',
+                    Self::PathStyle => ';',
+                    Self::Query | Self::Continuation => '&',
+                }
+            }
+
+            fn named(self) -> bool {
+                matches!(self, Self::PathStyle | Self::Query | Self::Continuation)
+            }
+
+            fn allows_reserved(self) -> bool {
+                matches!(self, Self::Reserved | Self::Fragment)
+            }
+
+            /// The `ifemp` string RFC 6570 substitutes for `=` when a named variable is bound to
+            /// a defined-but-empty value: path-style (`;`) variables render bare (`;name`), while
+            /// query and continuation (`?`/`&`) variables still render the `=` (`name=`).
+            fn ifemp(self) -> &'static str {
+                match self {
+                    Self::PathStyle => "",
+                    _ => "=",
+                }
+            }
+        }
+
+        struct VarSpec<'a> {
+            name: &'a str,
+            prefix: Option<usize>,
+            explode: bool,
+        }
+
+        fn parse_varspec(raw: &str) -> Result<VarSpec<'_>, ExpandError> {
+            if let Some(name) = raw.strip_suffix('*') {
+                return Ok(VarSpec {
+                    name,
+                    prefix: None,
+                    explode: true,
+                });
+            }
+
+            if let Some((name, len)) = raw.split_once(':') {
+                let prefix = len
+                    .parse::<usize>()
+                    .map_err(|_| ExpandError::InvalidPrefixLength(len.to_string()))?;
+                return Ok(VarSpec {
+                    name,
+                    prefix: Some(prefix),
+                    explode: false,
+                });
+            }
+
+            Ok(VarSpec {
+                name: raw,
+                prefix: None,
+                explode: false,
+            })
+        }
+
+        fn is_unreserved(b: u8) -> bool {
+            b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~')
+        }
+
+        fn is_reserved(b: u8) -> bool {
+            matches!(
+                b,
+                b':' | b'/'
+                    | b'?'
+                    | b'#'
+                    | b'['
+                    | b']'
+                    | b'@'
+                    | b'!'
+                    | b'$'
+                    | b'&'
+                    | b'\''
+                    | b'('
+                    | b')'
+                    | b'*'
+                    | b'+'
+                    | b','
+                    | b';'
+                    | b'='
+            )
+        }
+
+        fn pct_encode(s: &str, allow_reserved: bool) -> String {
+            let mut out = String::with_capacity(s.len());
+            for b in s.bytes() {
+                if is_unreserved(b) || (allow_reserved && is_reserved(b)) {
+                    out.push(b as char);
+                } else {
+                    out.push_str(&format!("%{b:02X}"));
+                }
+            }
+            out
+        }
+
+        fn apply_prefix(s: &str, prefix: Option<usize>) -> String {
+            match prefix {
+                Some(n) => s.chars().take(n).collect(),
+                None => s.to_string(),
+            }
+        }
+
+        fn render_varspec(spec: &VarSpec<'_>, value: &UriValue, operator: Operator) -> Option<String> {
+            let named = |name: &str, encoded: String| {
+                if encoded.is_empty() {
+                    format!("{name}{}", operator.ifemp())
+                } else {
+                    format!("{name}={encoded}")
+                }
+            };
+
+            match value {
+                UriValue::Scalar(s) => {
+                    let truncated = apply_prefix(s, spec.prefix);
+                    let encoded = pct_encode(&truncated, operator.allows_reserved());
+                    Some(if operator.named() {
+                        named(spec.name, encoded)
+                    } else {
+                        encoded
+                    })
+                }
+                UriValue::List(items) => {
+                    if items.is_empty() {
+                        return None;
+                    }
+
+                    if spec.explode {
+                        let sep = operator.separator().to_string();
+                        let parts: Vec<String> = items
+                            .iter()
+                            .map(|v| {
+                                let encoded = pct_encode(v, operator.allows_reserved());
+                                if operator.named() {
+                                    named(spec.name, encoded)
+                                } else {
+                                    encoded
+                                }
+                            })
+                            .collect();
+                        Some(parts.join(&sep))
+                    } else {
+                        let joined = items
+                            .iter()
+                            .map(|v| pct_encode(v, operator.allows_reserved()))
+                            .collect::<Vec<_>>()
+                            .join(",");
+                        Some(if operator.named() {
+                            named(spec.name, joined)
+                        } else {
+                            joined
+                        })
+                    }
+                }
+                UriValue::Assoc(pairs) => {
+                    if pairs.is_empty() {
+                        return None;
+                    }
+
+                    if spec.explode {
+                        let sep = operator.separator().to_string();
+                        let parts: Vec<String> = pairs
+                            .iter()
+                            .map(|(k, v)| {
+                                let key = pct_encode(k, operator.allows_reserved());
+                                let value = pct_encode(v, operator.allows_reserved());
+                                named(&key, value)
+                            })
+                            .collect();
+                        Some(parts.join(&sep))
+                    } else {
+                        let joined = pairs
+                            .iter()
+                            .flat_map(|(k, v)| {
+                                [
+                                    pct_encode(k, operator.allows_reserved()),
+                                    pct_encode(v, operator.allows_reserved()),
+                                ]
+                            })
+                            .collect::<Vec<_>>()
+                            .join(",");
+                        Some(if operator.named() {
+                            named(spec.name, joined)
+                        } else {
+                            joined
+                        })
+                    }
+                }
+            }
+        }
+
+        fn expand_expression(
+            expr: &str,
+            vars: &BTreeMap<String, UriValue>,
+        ) -> Result<String, ExpandError> {
+            if expr.is_empty() {
+                return Err(ExpandError::EmptyExpression);
+            }
+
+            let mut chars = expr.chars();
+            let first = chars.next().unwrap();
+            let (operator, rest) = match Operator::from_char(first) {
+                Some(operator) => (operator, chars.as_str()),
+                None => (Operator::Simple, expr),
+            };
+
+            let rendered = rest
+                .split(',')
+                .map(parse_varspec)
+                .collect::<Result<Vec<_>, _>>()?
+                .iter()
+                .filter_map(|spec| {
+                    vars.get(spec.name)
+                        .and_then(|value| render_varspec(spec, value, operator))
+                })
+                .collect::<Vec<_>>();
+
+            if rendered.is_empty() {
+                return Ok(String::new());
+            }
+
+            let sep = operator.separator().to_string();
+            let mut out = String::new();
+            if let Some(prefix) = operator.prefix() {
+                out.push(prefix);
+            }
+            out.push_str(&rendered.join(&sep));
+            Ok(out)
+        }
+
+        /// Expand a URI Template, resolving each `{...}` expression against `vars`.
+        pub fn expand(
+            template: &str,
+            vars: &BTreeMap<String, UriValue>,
+        ) -> Result<String, ExpandError> {
+            let mut out = String::with_capacity(template.len());
+            let bytes = template.as_bytes();
+            let mut i = 0;
+
+            while i < bytes.len() {
+                if bytes[i] == b'{' {
+                    let len = template[i..]
+                        .find('}')
+                        .ok_or(ExpandError::UnterminatedExpression)?;
+                    let expr = &template[i + 1..i + len];
+                    out.push_str(&expand_expression(expr, vars)?);
+                    i += len + 1;
+                } else {
+                    let next = template[i..]
+                        .find('{')
+                        .map_or(template.len(), |pos| i + pos);
+                    out.push_str(&pct_encode(&template[i..next], true));
+                    i = next;
+                }
+            }
+
+            Ok(out)
+        }
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+
+            fn vars() -> BTreeMap<String, UriValue> {
+                let mut vars = BTreeMap::new();
+                vars.insert("offset".to_string(), UriValue::Scalar("10".to_string()));
+                vars.insert(
+                    "format".to_string(),
+                    UriValue::List(vec!["json".to_string(), "ld".to_string()]),
+                );
+                vars.insert(
+                    "filter".to_string(),
+                    UriValue::Assoc(vec![
+                        ("key".to_string(), "name".to_string()),
+                        ("value".to_string(), "foo".to_string()),
+                    ]),
+                );
+                vars
+            }
+
+            #[test]
+            fn simple_expansion() {
+                let out = expand("/things{?offset,limit,format,sort_by,sort_order}", &vars()).unwrap();
+                assert_eq!(out, "/things?offset=10&format=json,ld");
+            }
+
+            #[test]
+            fn exploded_list_in_query() {
+                let out = expand("/things{?format*}", &vars()).unwrap();
+                assert_eq!(out, "/things?format=json&format=ld");
+            }
+
+            #[test]
+            fn path_and_label_operators() {
+                let out = expand("/things{/offset}{.offset}", &vars()).unwrap();
+                assert_eq!(out, "/things/10.10");
+            }
+
+            #[test]
+            fn exploded_assoc() {
+                let out = expand("{?filter*}", &vars()).unwrap();
+                assert_eq!(out, "?key=name&value=foo");
+            }
+
+            #[test]
+            fn undefined_variable_is_skipped() {
+                let out = expand("/things{?missing,offset}", &vars()).unwrap();
+                assert_eq!(out, "/things?offset=10");
+            }
+
+            #[test]
+            fn defined_empty_value_ifemp() {
+                let mut vars = BTreeMap::new();
+                vars.insert("empty".to_string(), UriValue::Scalar(String::new()));
+
+                assert_eq!(expand("{;empty}", &vars).unwrap(), ";empty");
+                assert_eq!(expand("{?empty}", &vars).unwrap(), "?empty=");
+                assert_eq!(expand("{&empty}", &vars).unwrap(), "&empty=");
+            }
+
+            #[test]
+            fn exploded_assoc_empty_value_ifemp() {
+                let mut vars = BTreeMap::new();
+                vars.insert(
+                    "filter".to_string(),
+                    UriValue::Assoc(vec![("key".to_string(), String::new())]),
+                );
+
+                assert_eq!(expand("{;filter*}", &vars).unwrap(), ";key");
+                assert_eq!(expand("{?filter*}", &vars).unwrap(), "?key=");
+            }
+
+            #[test]
+            fn reserved_operator_leaves_reserved_chars() {
+                let mut vars = BTreeMap::new();
+                vars.insert("path".to_string(), UriValue::Scalar("/a/b".to_string()));
+                let out = expand("{+path}", &vars).unwrap();
+                assert_eq!(out, "/a/b");
+            }
+
+            #[test]
+            fn prefix_modifier_truncates() {
+                let mut vars = BTreeMap::new();
+                vars.insert("var".to_string(), UriValue::Scalar("value".to_string()));
+                let out = expand("{var:3}", &vars).unwrap();
+                assert_eq!(out, "val");
+            }
+
+            #[test]
+            fn unterminated_expression_errors() {
+                assert_eq!(expand("{offset", &vars()), Err(ExpandError::UnterminatedExpression));
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::hlist::{Cons, Nil};
+    use mini::StatusCode as _;
 
     #[test]
     fn build_response() {
@@ -308,6 +1437,201 @@ mod test {
         dbg!(&b);
     }
 
+    #[test]
+    fn form_default_uses_default_content_type() {
+        let form = mini::Form::<Nil>::default();
+        assert_eq!(form.content_type, mini::Form::<Nil>::default_content_type());
+
+        let mut builder_form = mini::Form::<Nil>::builder();
+        builder_form.href = "/things".to_string();
+        builder_form.content_type = form.content_type.clone();
+        assert!(builder_form.try_build().is_ok());
+    }
+
+    #[test]
+    fn try_build_response_rejects_bad_status_code() {
+        let err = super::Response::builder()
+            .status_code_value(999)
+            .try_build()
+            .unwrap_err();
+
+        assert_eq!(err, BuildError::InvalidStatusCode(999));
+
+        super::Response::builder()
+            .status_code_value(200)
+            .try_build()
+            .unwrap();
+    }
+
+    #[test]
+    fn try_build_expected_response_rejects_bad_media_type() {
+        let err = mini::ExpectedResponse::<Nil>::builder()
+            .content_type("not-a-media-type")
+            .try_build()
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            BuildError::InvalidMediaType {
+                field: "content_type",
+                value: "not-a-media-type".to_string(),
+            }
+        );
+
+        mini::ExpectedResponse::<Nil>::builder()
+            .content_type("text/foo")
+            .try_build()
+            .unwrap();
+
+        mini::ExpectedResponse::<Nil>::builder()
+            .content_type("application/ld+json; charset=utf-8")
+            .try_build()
+            .unwrap();
+    }
+
+    #[test]
+    fn try_build_form_rejects_empty_href() {
+        let err = mini::Form::<Nil>::builder()
+            .content_type("application/json")
+            .try_build()
+            .unwrap_err();
+
+        assert_eq!(err, BuildError::EmptyHref);
+
+        mini::Form::<Nil>::builder()
+            .href("/things")
+            .content_type("application/json")
+            .try_build()
+            .unwrap();
+    }
+
+    #[test]
+    fn content_coding_and_security_scheme_ref_roundtrip_unknown_values() {
+        let f: mini::Form = serde_json::from_str(
+            r#"{
+                "href": "/things",
+                "contentCoding": "x-custom",
+                "security": "x-vendor-scheme"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            f.content_coding,
+            Some(mini::ContentCoding::Other("x-custom".to_string()))
+        );
+        assert_eq!(
+            f.security,
+            Some(vec![mini::SecuritySchemeRef::Other(
+                "x-vendor-scheme".to_string()
+            )])
+        );
+
+        let value = serde_json::to_value(&f).unwrap();
+        assert_eq!(value["contentCoding"], "x-custom");
+        assert_eq!(value["security"], "x-vendor-scheme");
+    }
+
+    #[test]
+    fn content_coding_and_security_scheme_ref_recognize_known_values() {
+        let f: mini::Form = serde_json::from_str(
+            r#"{
+                "href": "/things",
+                "contentCoding": "gzip",
+                "security": ["basic", "oauth2"]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(f.content_coding, Some(mini::ContentCoding::Gzip));
+        assert_eq!(
+            f.security,
+            Some(vec![
+                mini::SecuritySchemeRef::Basic,
+                mini::SecuritySchemeRef::OAuth2
+            ])
+        );
+    }
+
+    #[test]
+    fn interaction_affordance_subscription_picks_observe_form() {
+        let affordance: mini::InteractionAffordance = serde_json::from_str(
+            r#"{
+                "forms": [
+                    {
+                        "href": "/things/lamp/properties/status",
+                        "op": ["readproperty"]
+                    },
+                    {
+                        "href": "/things/lamp/properties/status/observe",
+                        "op": ["observeproperty"],
+                        "subprotocol": "sse",
+                        "contentType": "text/event-stream"
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(affordance.observe_forms().count(), 1);
+        assert_eq!(affordance.subscribe_event_forms().count(), 0);
+
+        let subscription = affordance.subscription().unwrap();
+        assert_eq!(subscription.form.href, "/things/lamp/properties/status/observe");
+        assert_eq!(subscription.subprotocol, Some(mini::Subprotocol::Sse));
+        assert_eq!(subscription.content_type, "text/event-stream");
+    }
+
+    #[test]
+    fn classify_response_matches_declared_success_and_error() {
+        let f: mini::Form<Nil, super::Response> = serde_json::from_str(
+            r#"{
+                "href": "/things",
+                "response": {
+                    "contentType": "application/ld+json",
+                    "htv:statusCodeValue": 200,
+                    "htv:headers": [
+                        { "htv:fieldName": "Link" }
+                    ]
+                },
+                "additionalResponse": {
+                    "success": false,
+                    "contentType": "application/problem+json",
+                    "htv:statusCodeValue": 400
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            f.classify_response(200, "application/ld+json; charset=utf-8", &["Link"]),
+            mini::ResponseOutcome::Success {
+                content_type: "application/ld+json; charset=utf-8".to_string(),
+            }
+        );
+        assert_eq!(
+            f.classify_response(400, "application/problem+json", &[]),
+            mini::ResponseOutcome::Error {
+                status: 400,
+                content_type: "application/problem+json".to_string(),
+            }
+        );
+        assert_eq!(
+            f.classify_response(500, "text/plain", &[]),
+            mini::ResponseOutcome::Unexpected
+        );
+
+        // A status/content-type match without the response's required `Link` header is not a
+        // declared match.
+        assert_eq!(
+            f.classify_response(200, "application/ld+json; charset=utf-8", &[]),
+            mini::ResponseOutcome::Unexpected
+        );
+
+        assert!(f.response.as_ref().unwrap().other.headers_present(&["link"]));
+        assert!(!f.response.as_ref().unwrap().other.headers_present(&["location"]));
+    }
+
     fn deserialize_form(s: &str) {
         let f: super::Form = serde_json::from_str(s).unwrap();
 
@@ -358,6 +1682,32 @@ mod test {
 
         deserialize_form(property);
     }
+
+    #[test]
+    fn expand_discovery_property_href() {
+        let f: mini::Form = serde_json::from_str(
+            r#"{
+                "href": "/things{?offset,limit,format,sort_by,sort_order}",
+                "htv:methodName": "GET"
+            }"#,
+        )
+        .unwrap();
+
+        let mut vars = std::collections::BTreeMap::new();
+        vars.insert(
+            "offset".to_string(),
+            mini::uri_template::UriValue::Scalar("10".to_string()),
+        );
+        vars.insert(
+            "format".to_string(),
+            mini::uri_template::UriValue::Scalar("json".to_string()),
+        );
+
+        assert_eq!(
+            f.expand_href(&vars).unwrap(),
+            "/things?offset=10&format=json",
+        );
+    }
     /*
         #[test]
         fn deserialize_discovery_action() {